@@ -0,0 +1,73 @@
+use crate::verification::VerificationReport;
+use crate::{Mock, Request, ResponseTemplate};
+
+/// A [`Mock`] that has been registered with an [`ActiveMockSet`](crate::mock_set::ActiveMockSet)
+/// and is now eligible to be matched against incoming requests.
+pub(crate) struct ActiveMock {
+    mock: Mock,
+    /// The position at which this mock was registered with its [`ActiveMockSet`](crate::mock_set::ActiveMockSet).
+    ///
+    /// Used to report verification failures against a stable identifier and to break ties
+    /// between mocks that share the same priority (earliest registration wins).
+    index: usize,
+    /// How many requests this mock has matched so far.
+    n_matches_so_far: u64,
+    /// The maximum number of requests this mock is allowed to match, set via
+    /// [`Mock::up_to_n_times`]. Once `n_matches_so_far` reaches this limit the mock stops being
+    /// eligible, letting another, still-available, mock answer instead.
+    max_n_matches: Option<u64>,
+    /// De-activated via [`ActiveMockSet::deactivate`](crate::mock_set::ActiveMockSet::deactivate) -
+    /// it stops matching against incoming requests, regardless of its specification.
+    pub(crate) active: bool,
+}
+
+impl ActiveMock {
+    pub(crate) fn new(mock: Mock, index: usize) -> ActiveMock {
+        let max_n_matches = mock.max_n_matches();
+        ActiveMock {
+            mock,
+            index,
+            n_matches_so_far: 0,
+            max_n_matches,
+            active: true,
+        }
+    }
+
+    /// Check if this mock matches the incoming request and still has room left under its
+    /// [`Mock::up_to_n_times`] limit, without recording the match.
+    ///
+    /// Call [`ActiveMock::record_match`] once you have decided that this is, among all the
+    /// mocks matching the request, the one that should handle it.
+    pub(crate) fn matches(&self, request: &Request) -> bool {
+        let has_capacity = match self.max_n_matches {
+            Some(max_n_matches) => self.n_matches_so_far < max_n_matches,
+            None => true,
+        };
+        self.active && has_capacity && self.mock.matches(request)
+    }
+
+    /// The priority assigned to the underlying [`Mock`] via [`Mock::with_priority`] - lower
+    /// values win when more than one mock matches the same incoming request.
+    pub(crate) fn priority(&self) -> u8 {
+        self.mock.priority()
+    }
+
+    /// Record that this mock has been chosen to handle an incoming request.
+    pub(crate) fn record_match(&mut self) {
+        self.n_matches_so_far += 1;
+    }
+
+    pub(crate) fn response_template(&self, request: &Request) -> ResponseTemplate {
+        self.mock.response_template(request)
+    }
+
+    /// Verify that this mock has been called as many times as expected.
+    pub(crate) fn verify(&self) -> VerificationReport {
+        VerificationReport::new(
+            self.index,
+            self.mock.name(),
+            self.mock.expectation_range(),
+            self.n_matches_so_far,
+        )
+    }
+}
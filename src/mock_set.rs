@@ -1,8 +1,9 @@
 use crate::{
     active_mock::ActiveMock,
+    request_recorder::RequestRecorder,
     verification::{VerificationOutcome, VerificationReport},
 };
-use crate::{Mock, Request, ResponseTemplate};
+use crate::{matchers::Match, Mock, Request, ResponseTemplate};
 use futures_timer::Delay;
 use http_types::{Response, StatusCode};
 use log::debug;
@@ -15,13 +16,22 @@ use std::ops::{Index, IndexMut};
 /// [`MockServer::register_scoped`](crate::MockServer::register_scoped) or
 /// [`Mock::mount`](crate::Mock::mount) are called.
 pub(crate) struct ActiveMockSet {
-    mocks: Vec<ActiveMock>,
+    /// Slots are kept around (as `None`) after a [`ActiveMockSet::remove`] rather than being
+    /// compacted, so that the index carried by every other [`MockId`] stays valid.
+    mocks: Vec<Option<ActiveMock>>,
     /// A counter that keeps track of how many times [`ActiveMockSet::reset`] has been called.
     /// It starts at `0` and gets incremented for each invocation.
     ///
     /// We need `generation` to know if a [`MockId`] points to an [`ActiveMock`] that has been
     /// removed via [`ActixMockSet::reset`].
     generation: u16,
+    /// The journal of every request seen by [`ActiveMockSet::handle_request`], regardless of
+    /// whether it matched a registered mock.
+    request_recorder: RequestRecorder,
+    /// The response generated by [`ActiveMockSet::handle_request`] when no registered mock
+    /// matches the incoming request. Defaults to a plain `404 Not Found` when unset, via
+    /// [`MockServer::set_default_response`](crate::MockServer::set_default_response).
+    default_response_template: Option<ResponseTemplate>,
 }
 
 /// A `MockId` is an opaque index that uniquely identifies an [`ActiveMock`] inside an [`ActiveMockSet`].  
@@ -42,34 +52,66 @@ impl ActiveMockSet {
         ActiveMockSet {
             mocks: vec![],
             generation: 0,
+            request_recorder: RequestRecorder::new(true),
+            default_response_template: None,
         }
     }
 
+    /// Set the response generated for requests that do not match any registered mock.
+    pub(crate) fn set_default_response_template(&mut self, response_template: ResponseTemplate) {
+        self.default_response_template = Some(response_template);
+    }
+
     pub(crate) async fn handle_request(&mut self, request: Request) -> (Response, Option<Delay>) {
         debug!("Handling request.");
-        let mut response_template: Option<ResponseTemplate> = None;
-        for mock in &mut self.mocks {
+        self.request_recorder.record(request.clone());
+        // Among all the mocks matching this request, the one with the lowest priority value
+        // wins; ties are broken by registration order (the earliest registered mock wins).
+        let mut best_match: Option<(usize, u8)> = None;
+        for (index, mock) in self
+            .mocks
+            .iter()
+            .enumerate()
+            .filter_map(|(i, m)| m.as_ref().map(|m| (i, m)))
+        {
             if mock.matches(&request) {
-                response_template = Some(mock.response_template(&request));
-                break;
+                let priority = mock.priority();
+                let is_better_match = match best_match {
+                    Some((_, best_priority)) => priority < best_priority,
+                    None => true,
+                };
+                if is_better_match {
+                    best_match = Some((index, priority));
+                }
             }
         }
-        if let Some(response_template) = response_template {
+        if let Some((index, _)) = best_match {
+            let mock = self.mocks[index]
+                .as_mut()
+                .expect("The best match must still be in the set.");
+            mock.record_match();
+            let response_template = mock.response_template(&request);
             let delay = response_template.delay().map(|d| Delay::new(d.to_owned()));
             (response_template.generate_response(), delay)
         } else {
             debug!("Got unexpected request:\n{}", request);
-            (Response::new(StatusCode::NotFound), None)
+            match &self.default_response_template {
+                Some(response_template) => {
+                    let delay = response_template.delay().map(|d| Delay::new(d.to_owned()));
+                    (response_template.generate_response(), delay)
+                }
+                None => (Response::new(StatusCode::NotFound), None),
+            }
         }
     }
 
     pub(crate) fn register(&mut self, mock: Mock) -> MockId {
-        let n_registered_mocks = self.mocks.len();
-        let active_mock = ActiveMock::new(mock, n_registered_mocks);
-        self.mocks.push(active_mock);
+        let index = self.mocks.len();
+        let active_mock = ActiveMock::new(mock, index);
+        self.mocks.push(Some(active_mock));
 
         MockId {
-            index: self.mocks.len() - 1,
+            index,
             generation: self.generation,
         }
     }
@@ -77,6 +119,32 @@ impl ActiveMockSet {
     pub(crate) fn reset(&mut self) {
         self.mocks = vec![];
         self.generation += 1;
+        self.request_recorder.reset();
+    }
+
+    /// Enable or disable the request journal. Disabling it is useful for long-running or
+    /// perf-sensitive tests that have no need to inspect past requests.
+    pub(crate) fn set_request_recording(&mut self, is_enabled: bool) {
+        self.request_recorder.set_enabled(is_enabled);
+    }
+
+    /// All requests received so far, regardless of whether they matched a registered mock.
+    pub(crate) fn received_requests(&self) -> &[Request] {
+        self.request_recorder.received_requests()
+    }
+
+    /// All received requests satisfying `matcher`.
+    pub(crate) fn find_received(&self, matcher: &dyn Match) -> Vec<&Request> {
+        self.request_recorder.find_received(matcher)
+    }
+
+    /// Remove a single mock from the set, without affecting any other [`MockId`].
+    ///
+    /// The vacated slot is kept as a tombstone rather than being compacted out of `mocks`, so
+    /// every other `MockId`'s index remains valid.
+    pub(crate) fn remove(&mut self, mock_id: MockId) {
+        self.index_mut(mock_id);
+        self.mocks[mock_id.index] = None;
     }
 
     /// De-activate one of the mocks in the set. It will stop matching against incoming requests,
@@ -91,6 +159,7 @@ impl ActiveMockSet {
         let failed_verifications: Vec<VerificationReport> = self
             .mocks
             .iter()
+            .filter_map(|mock| mock.as_ref())
             .map(ActiveMock::verify)
             .filter(|verification_report| !verification_report.is_satisfied())
             .collect();
@@ -113,7 +182,9 @@ impl IndexMut<MockId> for ActiveMockSet {
         if index.generation != self.generation {
             panic!("The mock you are trying to access is no longer active. It has been deleted from the active set via `reset` - you should not hold on to a `MockId` after you call `reset`!.")
         }
-        &mut self.mocks[index.index]
+        self.mocks[index.index]
+            .as_mut()
+            .expect("The mock you are trying to access has been removed from the active set via `remove`.")
     }
 }
 
@@ -124,7 +195,9 @@ impl Index<MockId> for ActiveMockSet {
         if index.generation != self.generation {
             panic!("The mock you are trying to access is no longer active. It has been deleted from the active set via `reset` - you should not hold on to a `MockId` after you call `reset`!.")
         }
-        &self.mocks[index.index]
+        self.mocks[index.index]
+            .as_ref()
+            .expect("The mock you are trying to access has been removed from the active set via `remove`.")
     }
 }
 
@@ -0,0 +1,49 @@
+use crate::matchers::Match;
+use crate::Request;
+
+/// Keeps a log of every [`Request`] seen by [`ActiveMockSet::handle_request`](crate::mock_set::ActiveMockSet::handle_request),
+/// regardless of whether it matched a registered mock.
+///
+/// This lets tests assert on requests that did NOT correspond to any registered mock - e.g.
+/// "the client retried `POST /x` three times with increasing backoff headers".
+pub(crate) struct RequestRecorder {
+    requests: Vec<Request>,
+    /// When `false`, [`RequestRecorder::record`] is a no-op - useful for long-running or
+    /// perf-sensitive tests that have no need for a request journal.
+    is_enabled: bool,
+}
+
+impl RequestRecorder {
+    pub(crate) fn new(is_enabled: bool) -> Self {
+        Self {
+            requests: vec![],
+            is_enabled,
+        }
+    }
+
+    pub(crate) fn set_enabled(&mut self, is_enabled: bool) {
+        self.is_enabled = is_enabled;
+    }
+
+    pub(crate) fn record(&mut self, request: Request) {
+        if self.is_enabled {
+            self.requests.push(request);
+        }
+    }
+
+    pub(crate) fn received_requests(&self) -> &[Request] {
+        &self.requests
+    }
+
+    /// Return all logged requests satisfying `matcher`.
+    pub(crate) fn find_received(&self, matcher: &dyn Match) -> Vec<&Request> {
+        self.requests
+            .iter()
+            .filter(|request| matcher.matches(request))
+            .collect()
+    }
+
+    pub(crate) fn reset(&mut self) {
+        self.requests = vec![];
+    }
+}
@@ -0,0 +1,56 @@
+use std::fmt;
+use std::ops::RangeInclusive;
+
+/// The outcome of verifying every [`ActiveMock`](crate::active_mock::ActiveMock) in an
+/// [`ActiveMockSet`](crate::mock_set::ActiveMockSet).
+pub(crate) enum VerificationOutcome {
+    Success,
+    Failure(Vec<VerificationReport>),
+}
+
+/// Whether a single mock's expectations, set via `Mock::expect`, have been honoured.
+pub(crate) struct VerificationReport {
+    /// The position at which the mock under verification was registered with its
+    /// [`ActiveMockSet`](crate::mock_set::ActiveMockSet).
+    index: usize,
+    /// The human-readable label attached via [`Mock::named`](crate::Mock::named), if any.
+    name: Option<String>,
+    expectation_range: RangeInclusive<u64>,
+    n_matched_requests: u64,
+}
+
+impl VerificationReport {
+    pub(crate) fn new(
+        index: usize,
+        name: Option<String>,
+        expectation_range: RangeInclusive<u64>,
+        n_matched_requests: u64,
+    ) -> Self {
+        Self {
+            index,
+            name,
+            expectation_range,
+            n_matched_requests,
+        }
+    }
+
+    pub(crate) fn is_satisfied(&self) -> bool {
+        self.expectation_range.contains(&self.n_matched_requests)
+    }
+}
+
+impl fmt::Display for VerificationReport {
+    /// Render a failure description identifying the mock by its `name`, falling back to its
+    /// registration index when it has none.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.name {
+            Some(name) => write!(f, "mock '{}'", name)?,
+            None => write!(f, "mock #{}", self.index)?,
+        }
+        write!(
+            f,
+            " expected {:?} matches but received {}",
+            self.expectation_range, self.n_matched_requests
+        )
+    }
+}